@@ -0,0 +1,143 @@
+//! Function composition.
+
+/// Compose two functions into one, running `f` and then `g`.
+///
+/// The result is a reusable [`Fn`], which can be named once and then passed
+/// to [`Apply::apply`](crate::Apply::apply) as often as needed. For `FnOnce`
+/// or `FnMut` inputs, see [`Compose`] or [`compose!`](crate::compose!).
+///
+/// ```
+/// use applying::{compose, Apply};
+///
+/// let shout = compose(str::trim, str::to_uppercase);
+///
+/// assert_eq!("HELLO", " hello ".apply(&shout));
+/// assert_eq!("THERE", "there  ".apply(&shout));
+/// ```
+pub fn compose<F, G, A, B, C>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(A) -> B,
+    G: Fn(B) -> C,
+{
+    move |a| g(f(a))
+}
+
+/// Compose functions in method-position.
+///
+/// Since the `Fn*` traits can't be implemented by hand on stable Rust, the
+/// kind of function produced is chosen by the method:
+///
+/// | Method                | Inputs   | Output   |
+/// |-----------------------|----------|----------|
+/// | [`Compose::then`]     | `FnOnce` | `FnOnce` |
+/// | [`Compose::then_mut`] | `FnMut`  | `FnMut`  |
+/// | [`Compose::then_fn`]  | `Fn`     | `Fn`     |
+///
+/// The [`compose!`](crate::compose!) macro instead matches the weakest of its
+/// inputs automatically.
+///
+/// ```
+/// use applying::{Apply, Compose};
+///
+/// let name = String::from("colin");
+/// let greet = str::len.then(move |n| format!("{name} ({n})"));
+///
+/// assert_eq!("colin (3)", "abc".apply(greet));
+/// ```
+pub trait Compose<A, B>: FnOnce(A) -> B + Sized {
+    /// Run this function, and then `g` on its output.
+    fn then<G, C>(self, g: G) -> impl FnOnce(A) -> C
+    where
+        G: FnOnce(B) -> C;
+
+    /// Like [`Compose::then`], but for functions that can be called repeatedly
+    /// while mutating their state.
+    ///
+    /// ```
+    /// use applying::Compose;
+    ///
+    /// let mut calls = 0;
+    /// let mut count = (|n: u32| n + 1).then_mut(|n| {
+    ///     calls += 1;
+    ///     n * 2
+    /// });
+    ///
+    /// assert_eq!(4, count(1));
+    /// assert_eq!(6, count(2));
+    /// drop(count);
+    /// assert_eq!(2, calls);
+    /// ```
+    fn then_mut<G, C>(self, g: G) -> impl FnMut(A) -> C
+    where
+        Self: FnMut(A) -> B,
+        G: FnMut(B) -> C;
+
+    /// Like [`Compose::then`], but for functions that can be called repeatedly
+    /// without mutating any state.
+    ///
+    /// ```
+    /// use applying::Compose;
+    ///
+    /// let f = u32::count_ones.then_fn(|n| n * 10);
+    ///
+    /// assert_eq!(10, f(1));
+    /// assert_eq!(30, f(7));
+    /// ```
+    fn then_fn<G, C>(self, g: G) -> impl Fn(A) -> C
+    where
+        Self: Fn(A) -> B,
+        G: Fn(B) -> C;
+}
+
+impl<F, A, B> Compose<A, B> for F
+where
+    F: FnOnce(A) -> B,
+{
+    fn then<G, C>(self, g: G) -> impl FnOnce(A) -> C
+    where
+        G: FnOnce(B) -> C,
+    {
+        move |a| g(self(a))
+    }
+
+    fn then_mut<G, C>(mut self, mut g: G) -> impl FnMut(A) -> C
+    where
+        Self: FnMut(A) -> B,
+        G: FnMut(B) -> C,
+    {
+        move |a| g(self(a))
+    }
+
+    fn then_fn<G, C>(self, g: G) -> impl Fn(A) -> C
+    where
+        Self: Fn(A) -> B,
+        G: Fn(B) -> C,
+    {
+        move |a| g(self(a))
+    }
+}
+
+/// Compose any number of functions, running them from left to right.
+///
+/// The result is a closure which is `Fn`, `FnMut` or `FnOnce` depending on the
+/// weakest of the functions given.
+///
+/// ```
+/// use applying::{compose, Apply};
+///
+/// let parse = compose!(str::trim, str::parse::<u32>, Result::unwrap_or_default);
+///
+/// assert_eq!(42, " 42 ".apply(&parse));
+/// assert_eq!(0, "x".apply(&parse));
+/// ```
+#[macro_export]
+macro_rules! compose {
+    ($f:expr $(,)?) => {
+        $f
+    };
+    ($f:expr, $($rest:expr),+ $(,)?) => {{
+        let f = $f;
+        let g = $crate::compose!($($rest),+);
+        move |a| g(f(a))
+    }};
+}
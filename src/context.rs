@@ -0,0 +1,173 @@
+//! Error context for fallible functions, via `anyhow` or `eyre`.
+
+use core::fmt::Display;
+
+/// Apply fallible functions in method-position, attaching [`anyhow`] context
+/// to their errors.
+///
+/// Works for functions returning either a `Result` or an `Option`.
+///
+/// ```
+/// use applying::AnyhowApply;
+///
+/// fn port(s: &str) -> anyhow::Result<u16> {
+///     s.try_apply_context("parsing port", str::parse::<u16>)
+/// }
+///
+/// fn first(xs: &[u16]) -> anyhow::Result<u16> {
+///     xs.try_apply_with_context(|| format!("{} ports", xs.len()), |xs| xs.first().copied())
+/// }
+///
+/// assert_eq!(8080, port("8080").unwrap());
+/// assert_eq!("parsing port", port("http").unwrap_err().to_string());
+/// assert_eq!("0 ports", first(&[]).unwrap_err().to_string());
+/// ```
+#[cfg(feature = "anyhow")]
+pub trait AnyhowApply: Sized {
+    /// Apply a fallible function, attaching the given context to any error.
+    fn try_apply_context<F, R, U, E, C>(self, context: C, f: F) -> anyhow::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: anyhow::Context<U, E>,
+        C: Display + Send + Sync + 'static;
+
+    /// Apply a fallible function, attaching lazily evaluated context to any
+    /// error.
+    fn try_apply_with_context<F, R, U, E, C, G>(self, context: G, f: F) -> anyhow::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: anyhow::Context<U, E>,
+        C: Display + Send + Sync + 'static,
+        G: FnOnce() -> C;
+}
+
+#[cfg(feature = "anyhow")]
+impl<T> AnyhowApply for T {
+    fn try_apply_context<F, R, U, E, C>(self, context: C, f: F) -> anyhow::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: anyhow::Context<U, E>,
+        C: Display + Send + Sync + 'static,
+    {
+        f(self).context(context)
+    }
+
+    fn try_apply_with_context<F, R, U, E, C, G>(self, context: G, f: F) -> anyhow::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: anyhow::Context<U, E>,
+        C: Display + Send + Sync + 'static,
+        G: FnOnce() -> C,
+    {
+        f(self).with_context(context)
+    }
+}
+
+/// Apply fallible functions in method-position, attaching [`eyre`] context to
+/// their errors.
+///
+/// Works for functions returning either a `Result` or an `Option`.
+///
+/// ```
+/// use applying::EyreApply;
+///
+/// fn port(s: &str) -> eyre::Result<u16> {
+///     s.try_apply_context("parsing port", str::parse::<u16>)
+/// }
+///
+/// fn first(xs: &[u16]) -> eyre::Result<u16> {
+///     xs.try_apply_with_context(|| format!("{} ports", xs.len()), |xs| xs.first().copied())
+/// }
+///
+/// fn privileged(p: u16) -> eyre::Result<u16> {
+///     if p < 1024 { Ok(p) } else { Err(eyre::eyre!("{p} is unprivileged")) }
+/// }
+///
+/// fn admin_port(s: &str) -> eyre::Result<u16> {
+///     port(s)?.try_apply_context("checking port", privileged)
+/// }
+///
+/// assert_eq!(8080, port("8080").unwrap());
+/// assert_eq!("parsing port", port("http").unwrap_err().to_string());
+/// assert_eq!("0 ports", first(&[]).unwrap_err().to_string());
+/// assert_eq!(80, admin_port("80").unwrap());
+/// assert_eq!("checking port", admin_port("8080").unwrap_err().to_string());
+/// ```
+#[cfg(feature = "eyre")]
+pub trait EyreApply: Sized {
+    /// Apply a fallible function, attaching the given context to any error.
+    fn try_apply_context<F, R, U, C>(self, context: C, f: F) -> eyre::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: EyreContext<U>,
+        C: Display + Send + Sync + 'static;
+
+    /// Apply a fallible function, attaching lazily evaluated context to any
+    /// error.
+    fn try_apply_with_context<F, R, U, C, G>(self, context: G, f: F) -> eyre::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: EyreContext<U>,
+        C: Display + Send + Sync + 'static,
+        G: FnOnce() -> C;
+}
+
+#[cfg(feature = "eyre")]
+impl<T> EyreApply for T {
+    fn try_apply_context<F, R, U, C>(self, context: C, f: F) -> eyre::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: EyreContext<U>,
+        C: Display + Send + Sync + 'static,
+    {
+        f(self).wrap_err_with(|| context)
+    }
+
+    fn try_apply_with_context<F, R, U, C, G>(self, context: G, f: F) -> eyre::Result<U>
+    where
+        F: FnOnce(Self) -> R,
+        R: EyreContext<U>,
+        C: Display + Send + Sync + 'static,
+        G: FnOnce() -> C,
+    {
+        f(self).wrap_err_with(context)
+    }
+}
+
+/// Values that [`EyreApply`] can attach context to.
+///
+/// `eyre` splits this between its `WrapErr` and `ContextCompat` traits, so this
+/// unifies them for `Result` and `Option`.
+#[cfg(feature = "eyre")]
+pub trait EyreContext<T> {
+    /// Wrap any error with lazily evaluated context.
+    fn wrap_err_with<C, G>(self, context: G) -> eyre::Result<T>
+    where
+        C: Display + Send + Sync + 'static,
+        G: FnOnce() -> C;
+}
+
+#[cfg(feature = "eyre")]
+impl<T, E> EyreContext<T> for Result<T, E>
+where
+    Self: eyre::WrapErr<T, E>,
+{
+    fn wrap_err_with<C, G>(self, context: G) -> eyre::Result<T>
+    where
+        C: Display + Send + Sync + 'static,
+        G: FnOnce() -> C,
+    {
+        eyre::WrapErr::wrap_err_with(self, context)
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl<T> EyreContext<T> for Option<T> {
+    fn wrap_err_with<C, G>(self, context: G) -> eyre::Result<T>
+    where
+        C: Display + Send + Sync + 'static,
+        G: FnOnce() -> C,
+    {
+        eyre::ContextCompat::wrap_err_with(self, context)
+    }
+}
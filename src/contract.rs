@@ -0,0 +1,186 @@
+//! Inline assertions and contract checks.
+
+use core::fmt;
+use core::panic::Location;
+
+/// Check invariants in the middle of a method chain.
+///
+/// ```
+/// use applying::{Apply, Contract};
+///
+/// let mean = vec![2, 4, 6]
+///     .apply_assert(|xs| !xs.is_empty(), "no values")
+///     .apply(|xs| xs.iter().sum::<u32>() / xs.len() as u32);
+///
+/// assert_eq!(4, mean);
+/// ```
+pub trait Contract: Sized {
+    /// Panic with the given message if the value doesn't satisfy the
+    /// predicate, otherwise return it.
+    ///
+    /// ```should_panic
+    /// use applying::Contract;
+    ///
+    /// let _ = 5.apply_assert(|n| n % 2 == 0, "not even");
+    /// ```
+    #[track_caller]
+    fn apply_assert<P>(self, pred: P, msg: &str) -> Self
+    where
+        P: FnOnce(&Self) -> bool;
+
+    /// Like [`Contract::apply_assert`], but only checked in debug builds.
+    #[track_caller]
+    fn apply_debug_assert<P>(self, pred: P, msg: &str) -> Self
+    where
+        P: FnOnce(&Self) -> bool;
+
+    /// Apply a function, panicking if the precondition doesn't hold for the
+    /// input, or the postcondition doesn't hold for the input and output.
+    ///
+    /// Since the postcondition needs to see the input as well, the function
+    /// borrows it.
+    ///
+    /// ```
+    /// use applying::Contract;
+    ///
+    /// let root = 49.0f64.apply_checked(
+    ///     |n| *n >= 0.0,
+    ///     |n| n.sqrt(),
+    ///     |n, r| (r * r - n).abs() < 1e-9,
+    /// );
+    ///
+    /// assert_eq!(7.0, root);
+    /// ```
+    #[track_caller]
+    fn apply_checked<Pre, F, Post, U>(self, pre: Pre, f: F, post: Post) -> U
+    where
+        Pre: FnOnce(&Self) -> bool,
+        F: FnOnce(&Self) -> U,
+        Post: FnOnce(&Self, &U) -> bool;
+
+    /// Like [`Contract::apply_checked`], but yields a [`ContractViolation`]
+    /// instead of panicking.
+    ///
+    /// ```
+    /// use applying::{Contract, Violation};
+    ///
+    /// let e = (-1.0f64)
+    ///     .try_apply_checked(|n| *n >= 0.0, |n| n.sqrt(), |_, r| !r.is_nan())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(Violation::Precondition, e.violation);
+    /// ```
+    #[track_caller]
+    fn try_apply_checked<Pre, F, Post, U>(
+        self,
+        pre: Pre,
+        f: F,
+        post: Post,
+    ) -> Result<U, ContractViolation>
+    where
+        Pre: FnOnce(&Self) -> bool,
+        F: FnOnce(&Self) -> U,
+        Post: FnOnce(&Self, &U) -> bool;
+}
+
+impl<T> Contract for T {
+    #[track_caller]
+    fn apply_assert<P>(self, pred: P, msg: &str) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        assert!(pred(&self), "Assertion failed: {}", msg);
+        self
+    }
+
+    #[track_caller]
+    fn apply_debug_assert<P>(self, pred: P, msg: &str) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        debug_assert!(pred(&self), "Assertion failed: {}", msg);
+        self
+    }
+
+    #[track_caller]
+    fn apply_checked<Pre, F, Post, U>(self, pre: Pre, f: F, post: Post) -> U
+    where
+        Pre: FnOnce(&Self) -> bool,
+        F: FnOnce(&Self) -> U,
+        Post: FnOnce(&Self, &U) -> bool,
+    {
+        match self.try_apply_checked(pre, f, post) {
+            Ok(u) => u,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[track_caller]
+    fn try_apply_checked<Pre, F, Post, U>(
+        self,
+        pre: Pre,
+        f: F,
+        post: Post,
+    ) -> Result<U, ContractViolation>
+    where
+        Pre: FnOnce(&Self) -> bool,
+        F: FnOnce(&Self) -> U,
+        Post: FnOnce(&Self, &U) -> bool,
+    {
+        let location = Location::caller();
+
+        if !pre(&self) {
+            return Err(ContractViolation {
+                violation: Violation::Precondition,
+                location,
+            });
+        }
+
+        let output = f(&self);
+
+        if !post(&self, &output) {
+            return Err(ContractViolation {
+                violation: Violation::Postcondition,
+                location,
+            });
+        }
+
+        Ok(output)
+    }
+}
+
+/// A failed contract check, as reported by
+/// [`Contract::try_apply_checked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractViolation {
+    /// Which condition failed.
+    pub violation: Violation,
+    /// Where the check was made.
+    pub location: &'static Location<'static>,
+}
+
+impl fmt::Display for ContractViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} violated at {}", self.violation, self.location)
+    }
+}
+
+impl core::error::Error for ContractViolation {}
+
+/// The kinds of condition in a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// The input didn't satisfy the precondition.
+    Precondition,
+    /// The input and output didn't satisfy the postcondition.
+    Postcondition,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Precondition => write!(f, "Precondition"),
+            Violation::Postcondition => write!(f, "Postcondition"),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Type conversions in method-position.
+
+use core::any::type_name;
+use core::fmt;
+use core::str::FromStr;
+
+/// Convert values in method-position.
+///
+/// Unlike `.apply(Into::into)`, the target type can be given with a turbofish,
+/// which keeps type inference happy in the middle of a chain.
+///
+/// ```
+/// use applying::Conv;
+///
+/// let n = 7u8.conv::<u64>().pow(2);
+/// assert_eq!(49, n);
+///
+/// let m = "42".parse_as::<i32>().unwrap().try_conv::<u8>().unwrap();
+/// assert_eq!(42, m);
+/// ```
+pub trait Conv: Sized {
+    /// Convert this value via [`Into`].
+    fn conv<T>(self) -> T
+    where
+        Self: Into<T>;
+
+    /// Convert this value via [`TryInto`].
+    ///
+    /// ```
+    /// use applying::Conv;
+    ///
+    /// let e = 300i32.try_conv::<u8>().unwrap_err();
+    /// assert_eq!("i32", e.from);
+    /// assert_eq!("u8", e.to);
+    /// ```
+    fn try_conv<T>(self) -> Result<T, ConvError<<Self as TryInto<T>>::Error>>
+    where
+        Self: TryInto<T>;
+
+    /// Parse this string into a value via [`FromStr`].
+    ///
+    /// ```
+    /// use applying::Conv;
+    ///
+    /// let e = "abc".parse_as::<u32>().unwrap_err();
+    /// assert_eq!(
+    ///     "Failed to convert &str into u32: invalid digit found in string",
+    ///     e.to_string()
+    /// );
+    /// ```
+    fn parse_as<T>(self) -> Result<T, ConvError<T::Err>>
+    where
+        Self: AsRef<str>,
+        T: FromStr;
+}
+
+impl<S> Conv for S {
+    fn conv<T>(self) -> T
+    where
+        Self: Into<T>,
+    {
+        self.into()
+    }
+
+    fn try_conv<T>(self) -> Result<T, ConvError<<Self as TryInto<T>>::Error>>
+    where
+        Self: TryInto<T>,
+    {
+        self.try_into().map_err(ConvError::new::<Self, T>)
+    }
+
+    fn parse_as<T>(self) -> Result<T, ConvError<T::Err>>
+    where
+        Self: AsRef<str>,
+        T: FromStr,
+    {
+        self.as_ref().parse().map_err(ConvError::new::<Self, T>)
+    }
+}
+
+/// A failed conversion, recording the types involved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvError<E> {
+    /// The name of the type being converted from.
+    pub from: &'static str,
+    /// The name of the type being converted into.
+    pub to: &'static str,
+    /// The original error.
+    pub error: E,
+}
+
+impl<E> ConvError<E> {
+    fn new<A, B>(error: E) -> Self {
+        ConvError {
+            from: type_name::<A>(),
+            to: type_name::<B>(),
+            error,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ConvError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to convert {} into {}: {}",
+            self.from, self.to, self.error
+        )
+    }
+}
+
+impl<E> core::error::Error for ConvError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
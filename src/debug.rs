@@ -0,0 +1,139 @@
+//! Debug printing in method-position.
+
+use core::fmt::Debug;
+use std::io::Write;
+
+/// Print values mid-chain, like [`dbg!`](std::dbg!), without giving up ownership.
+///
+/// Each value is printed with the file and line it was printed from. Printing
+/// only happens in debug builds, and not at all when the `no-dbg` feature is
+/// enabled.
+///
+/// ```
+/// use applying::{apply_dbg, Apply, Dbg};
+///
+/// let n = "42"
+///     .apply_dbg() // [src/main.rs:4] "42"
+///     .parse::<u32>()
+///     .unwrap()
+///     .apply(apply_dbg!("parsed")); // [src/main.rs:7] parsed = 42
+///
+/// assert_eq!(42, n);
+/// ```
+pub trait Dbg: Debug + Sized {
+    /// Print this value to stderr, then return it.
+    fn apply_dbg(self) -> Self;
+
+    /// Print this value to the given writer, then return it.
+    ///
+    /// ```
+    /// use applying::Dbg;
+    ///
+    /// let mut out = Vec::new();
+    /// let n = 7.apply_dbg_to(&mut out);
+    ///
+    /// assert_eq!(7, n);
+    ///
+    /// // In debug builds, `out` now holds "[src/main.rs:4] 7\n". Nothing is
+    /// // written in release builds, or with `no-dbg`.
+    /// ```
+    fn apply_dbg_to<W: Write>(self, writer: W) -> Self;
+}
+
+impl<T: Debug> Dbg for T {
+    #[track_caller]
+    fn apply_dbg(self) -> Self {
+        let loc = core::panic::Location::caller();
+        print(std::io::stderr(), loc.file(), loc.line(), None, &self);
+        self
+    }
+
+    #[track_caller]
+    fn apply_dbg_to<W: Write>(self, writer: W) -> Self {
+        let loc = core::panic::Location::caller();
+        print(writer, loc.file(), loc.line(), None, &self);
+        self
+    }
+}
+
+/// Print a value to the given writer, as done by [`Dbg`] and
+/// [`apply_dbg!`](crate::apply_dbg!).
+#[doc(hidden)]
+pub fn print<W, T>(mut writer: W, file: &str, line: u32, label: Option<&str>, value: &T)
+where
+    W: Write,
+    T: Debug + ?Sized,
+{
+    if cfg!(all(debug_assertions, not(feature = "no-dbg"))) {
+        // Like `dbg!`, failures to write are ignored.
+        let _ = match label {
+            None => writeln!(writer, "[{}:{}] {:#?}", file, line, value),
+            Some(l) => writeln!(writer, "[{}:{}] {} = {:#?}", file, line, l, value),
+        };
+    }
+}
+
+/// Produce a function that prints its argument to stderr, then returns it.
+///
+/// Meant to be passed to [`Apply::apply`](crate::Apply::apply). An optional
+/// label is printed along with the value. See [`Dbg`] for more information.
+///
+/// ```
+/// use applying::{apply_dbg, Apply};
+///
+/// let xs = vec![1, 2, 3].apply(apply_dbg!()).apply(apply_dbg!("xs"));
+/// assert_eq!(3, xs.len());
+/// ```
+#[macro_export]
+macro_rules! apply_dbg {
+    () => {
+        |value| {
+            $crate::__dbg_print(::std::io::stderr(), file!(), line!(), None, &value);
+            value
+        }
+    };
+    ($label:expr $(,)?) => {
+        |value| {
+            $crate::__dbg_print(::std::io::stderr(), file!(), line!(), Some($label), &value);
+            value
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::String;
+    use std::vec::Vec;
+
+    const ENABLED: bool = cfg!(all(debug_assertions, not(feature = "no-dbg")));
+
+    #[test]
+    fn apply_dbg_to() {
+        let mut out = Vec::new();
+        let line = line!() + 1;
+        let n = 7.apply_dbg_to(&mut out);
+        let out = String::from_utf8(out).unwrap();
+
+        assert_eq!(7, n);
+
+        if ENABLED {
+            assert_eq!(std::format!("[{}:{}] 7\n", file!(), line), out);
+        } else {
+            assert!(out.is_empty());
+        }
+    }
+
+    #[test]
+    fn labelled() {
+        let mut out = Vec::new();
+        print(&mut out, "lib.rs", 3, Some("n"), &[1, 2]);
+        let out = String::from_utf8(out).unwrap();
+
+        if ENABLED {
+            assert_eq!("[lib.rs:3] n = [\n    1,\n    2,\n]\n", out);
+        } else {
+            assert!(out.is_empty());
+        }
+    }
+}
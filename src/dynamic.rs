@@ -0,0 +1,254 @@
+//! Pipelines of stages chosen at runtime.
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::{type_name, Any, TypeId};
+use core::fmt;
+
+type Erased = dyn Fn(Box<dyn Any>) -> Box<dyn Any> + Send + Sync;
+
+/// A type-erased stage of a [`DynPipeline`].
+///
+/// Remembers the input and output types of its function, so that pipelines
+/// can be checked for consistency before they're run.
+#[derive(Clone)]
+pub struct DynStage {
+    name: &'static str,
+    input: Type,
+    output: Type,
+    run: Arc<Erased>,
+}
+
+impl DynStage {
+    /// Erase the types of the given function.
+    pub fn new<F, A, B>(name: &'static str, f: F) -> Self
+    where
+        F: Fn(A) -> B + Send + Sync + 'static,
+        A: 'static,
+        B: 'static,
+    {
+        let run = move |a: Box<dyn Any>| -> Box<dyn Any> {
+            let a = a
+                .downcast::<A>()
+                .expect("stage input type checked by `DynPipeline`");
+            Box::new(f(*a))
+        };
+
+        DynStage {
+            name,
+            input: Type::of::<A>(),
+            output: Type::of::<B>(),
+            run: Arc::new(run),
+        }
+    }
+
+    /// The name of this stage.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The name of the type this stage accepts.
+    pub fn input_type(&self) -> &'static str {
+        self.input.name
+    }
+
+    /// The name of the type this stage produces.
+    pub fn output_type(&self) -> &'static str {
+        self.output.name
+    }
+}
+
+impl fmt::Debug for DynStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynStage")
+            .field("name", &self.name)
+            .field("input", &self.input.name)
+            .field("output", &self.output.name)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Type {
+    id: TypeId,
+    name: &'static str,
+}
+
+impl Type {
+    fn of<T: 'static>() -> Self {
+        Type {
+            id: TypeId::of::<T>(),
+            name: type_name::<T>(),
+        }
+    }
+}
+
+/// A pipeline of type-erased stages, checked for consistency at runtime.
+///
+/// Unlike [`Pipeline`](crate::Pipeline), the stages of a `DynPipeline` can be
+/// chosen while the program is running. Construction fails if the output type
+/// of any stage doesn't match the input type of the next.
+///
+/// ```
+/// use applying::{DynPipeline, DynPipelineError, DynStage};
+///
+/// let trim = DynStage::new("trim", |s: String| s.trim().to_string());
+/// let parse = DynStage::new("parse", |s: String| s.parse::<u32>().unwrap_or(0));
+/// let double = DynStage::new("double", |n: u32| n * 2);
+///
+/// let pipeline = DynPipeline::new([trim.clone(), parse.clone(), double.clone()]).unwrap();
+/// let n: u32 = pipeline.run(" 21 ".to_string()).unwrap();
+/// assert_eq!(42, n);
+///
+/// let bad = DynPipeline::new([double, trim]).unwrap_err();
+/// assert_eq!(
+///     "Stage 1 (trim) expects alloc::string::String, but stage 0 (double) produces u32",
+///     bad.to_string()
+/// );
+///
+/// let bad = pipeline.run::<&str, u32>("21").unwrap_err();
+/// assert!(matches!(bad, DynPipelineError::InputMismatch { .. }));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynPipeline {
+    stages: Vec<DynStage>,
+}
+
+impl DynPipeline {
+    /// Assemble a pipeline from the given stages, checking that the types of
+    /// adjacent stages line up.
+    pub fn new<I>(stages: I) -> Result<Self, DynPipelineError>
+    where
+        I: IntoIterator<Item = DynStage>,
+    {
+        let stages: Vec<DynStage> = stages.into_iter().collect();
+
+        for (index, pair) in stages.windows(2).enumerate() {
+            let (from, to) = (&pair[0], &pair[1]);
+
+            if from.output.id != to.input.id {
+                return Err(DynPipelineError::StageMismatch {
+                    index: index + 1,
+                    from: from.name,
+                    output: from.output.name,
+                    to: to.name,
+                    input: to.input.name,
+                });
+            }
+        }
+
+        Ok(DynPipeline { stages })
+    }
+
+    /// Run the pipeline, given that `I` and `O` match its input and output
+    /// types.
+    pub fn run<I, O>(&self, input: I) -> Result<O, DynPipelineError>
+    where
+        I: 'static,
+        O: 'static,
+    {
+        let given = Type::of::<I>();
+        let wanted = Type::of::<O>();
+        let input_type = self.stages.first().map_or(given, |s| s.input);
+        let output_type = self.stages.last().map_or(given, |s| s.output);
+
+        if input_type.id != given.id {
+            return Err(DynPipelineError::InputMismatch {
+                expected: input_type.name,
+                found: given.name,
+            });
+        }
+
+        if output_type.id != wanted.id {
+            return Err(DynPipelineError::OutputMismatch {
+                expected: output_type.name,
+                found: wanted.name,
+            });
+        }
+
+        let output = self
+            .stages
+            .iter()
+            .fold(Box::new(input) as Box<dyn Any>, |a, stage| (stage.run)(a));
+
+        // The types were all checked above, so this can't fail.
+        let output = output
+            .downcast::<O>()
+            .expect("pipeline output type checked by `DynPipeline::run`");
+
+        Ok(*output)
+    }
+
+    /// The stages of this pipeline, in order.
+    pub fn stages(&self) -> &[DynStage] {
+        &self.stages
+    }
+}
+
+/// A type mismatch within a [`DynPipeline`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynPipelineError {
+    /// The output of one stage doesn't match the input of the next.
+    StageMismatch {
+        /// The position of the stage whose input didn't match.
+        index: usize,
+        /// The name of the preceding stage.
+        from: &'static str,
+        /// The type produced by the preceding stage.
+        output: &'static str,
+        /// The name of the stage whose input didn't match.
+        to: &'static str,
+        /// The type expected by that stage.
+        input: &'static str,
+    },
+    /// The pipeline was run with the wrong input type.
+    InputMismatch {
+        /// The type the pipeline accepts.
+        expected: &'static str,
+        /// The type it was given.
+        found: &'static str,
+    },
+    /// The pipeline was asked for the wrong output type.
+    OutputMismatch {
+        /// The type the pipeline produces.
+        expected: &'static str,
+        /// The type that was asked for.
+        found: &'static str,
+    },
+}
+
+impl fmt::Display for DynPipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynPipelineError::StageMismatch {
+                index,
+                from,
+                output,
+                to,
+                input,
+            } => write!(
+                f,
+                "Stage {} ({}) expects {}, but stage {} ({}) produces {}",
+                index,
+                to,
+                input,
+                index.saturating_sub(1),
+                from,
+                output
+            ),
+            DynPipelineError::InputMismatch { expected, found } => write!(
+                f,
+                "Pipeline expects input of type {}, but was given {}",
+                expected, found
+            ),
+            DynPipelineError::OutputMismatch { expected, found } => write!(
+                f,
+                "Pipeline produces {}, but {} was asked for",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DynPipelineError {}
@@ -0,0 +1,150 @@
+//! Application over the contents of `Result` and `Option`.
+
+use crate::StageError;
+
+/// Apply functions to the contents of a [`Result`].
+///
+/// ```
+/// use applying::ApplyResult;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     Parse(std::num::ParseIntError),
+///     Negative,
+/// }
+///
+/// impl From<std::num::ParseIntError> for Error {
+///     fn from(e: std::num::ParseIntError) -> Self {
+///         Error::Parse(e)
+///     }
+/// }
+///
+/// fn positive(n: i32) -> Result<u32, Error> {
+///     u32::try_from(n).map_err(|_| Error::Negative)
+/// }
+///
+/// let n = Ok::<_, Error>("42")
+///     .try_apply(str::parse::<i32>)
+///     .try_apply(positive)
+///     .apply_ok(u32::count_ones);
+///
+/// assert_eq!(Ok(3), n);
+/// ```
+pub trait ApplyResult<T, E> {
+    /// Apply a function to the `Ok` value, leaving an `Err` untouched.
+    fn apply_ok<F, U>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> U;
+
+    /// Apply a function to the `Err` value, leaving an `Ok` untouched.
+    fn apply_err<F, X>(self, f: F) -> Result<T, X>
+    where
+        F: FnOnce(E) -> X;
+
+    /// Apply a fallible function to the `Ok` value.
+    ///
+    /// As with `?`, the function's error is converted into the error type of
+    /// the chain via [`From`].
+    fn try_apply<F, U, X>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, X>,
+        E: From<X>;
+
+    /// Like [`ApplyResult::try_apply`], but attributes the function's error to
+    /// the given stage by wrapping it in a [`StageError`].
+    ///
+    /// ```
+    /// use applying::{ApplyResult, StageError};
+    /// use std::num::ParseIntError;
+    ///
+    /// fn parse(s: &str) -> Result<u32, StageError<ParseIntError>> {
+    ///     Ok(s)
+    ///         .try_apply_stage("trim", 0, |s| Ok::<_, ParseIntError>(s.trim()))
+    ///         .try_apply_stage("parse", 1, str::parse::<u32>)
+    ///         .try_apply_stage("double", 2, |n| Ok::<_, ParseIntError>(n * 2))
+    /// }
+    ///
+    /// assert_eq!(Ok(84), parse(" 42 "));
+    ///
+    /// let e = parse("x").unwrap_err();
+    /// assert_eq!(("parse", 1), (e.stage, e.index));
+    /// ```
+    fn try_apply_stage<F, U, X>(self, stage: &'static str, index: usize, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, X>,
+        E: From<StageError<X>>;
+}
+
+impl<T, E> ApplyResult<T, E> for Result<T, E> {
+    fn apply_ok<F, U>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.map(f)
+    }
+
+    fn apply_err<F, X>(self, f: F) -> Result<T, X>
+    where
+        F: FnOnce(E) -> X,
+    {
+        self.map_err(f)
+    }
+
+    fn try_apply<F, U, X>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, X>,
+        E: From<X>,
+    {
+        match self {
+            Ok(t) => f(t).map_err(From::from),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn try_apply_stage<F, U, X>(self, stage: &'static str, index: usize, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, X>,
+        E: From<StageError<X>>,
+    {
+        self.try_apply(|t| f(t).map_err(|e| StageError::new(stage, index, e)))
+    }
+}
+
+/// Apply functions to the contents of an [`Option`].
+///
+/// ```
+/// use applying::ApplyOption;
+///
+/// let c = Some("hello")
+///     .try_apply(|s| s.chars().next())
+///     .apply_some(|c| c.to_ascii_uppercase());
+///
+/// assert_eq!(Some('H'), c);
+/// ```
+pub trait ApplyOption<T> {
+    /// Apply a function to the `Some` value, leaving `None` untouched.
+    fn apply_some<F, U>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> U;
+
+    /// Apply a function that may itself produce `None`.
+    fn try_apply<F, U>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> Option<U>;
+}
+
+impl<T> ApplyOption<T> for Option<T> {
+    fn apply_some<F, U>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.map(f)
+    }
+
+    fn try_apply<F, U>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        self.and_then(f)
+    }
+}
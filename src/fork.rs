@@ -0,0 +1,151 @@
+//! Applying several functions to the same value.
+
+/// Apply several functions to a value, collecting their results in a tuple.
+///
+/// Since Rust has no variadic functions, the functions are given as a tuple of
+/// up to 12 elements.
+///
+/// ```
+/// use applying::Fork;
+///
+/// let (len, upper, words) = "hello there".to_string().fork((
+///     |s: String| s.len(),
+///     |s: String| s.to_uppercase(),
+///     |s: String| s.split(' ').count(),
+/// ));
+///
+/// assert_eq!(11, len);
+/// assert_eq!("HELLO THERE", upper);
+/// assert_eq!(2, words);
+/// ```
+pub trait Fork {
+    /// Apply each function to a clone of this value.
+    ///
+    /// The last function receives the original, so one less clone is made
+    /// than there are functions.
+    fn fork<Fs>(self, fs: Fs) -> Fs::Output
+    where
+        Fs: Forks<Self>,
+        Self: Sized;
+
+    /// Apply each function to a reference to this value, avoiding any clones.
+    ///
+    /// ```
+    /// use applying::Fork;
+    ///
+    /// let xs = vec![3, 1, 2];
+    /// let (min, max, len) = xs.fork_ref((
+    ///     |xs: &Vec<u32>| xs.iter().min().copied(),
+    ///     |xs: &Vec<u32>| xs.iter().max().copied(),
+    ///     Vec::len,
+    /// ));
+    ///
+    /// assert_eq!((Some(1), Some(3), 3), (min, max, len));
+    /// ```
+    fn fork_ref<Fs>(&self, fs: Fs) -> Fs::Output
+    where
+        Fs: ForksRef<Self>;
+
+    /// Apply two functions to this value, the first to a clone of it.
+    ///
+    /// ```
+    /// use applying::Fork;
+    ///
+    /// let (n, s) = 7u32.apply_both(u32::count_ones, |n| n.to_string());
+    /// assert_eq!((3, "7".to_string()), (n, s));
+    /// ```
+    fn apply_both<F, G, A, B>(self, f: F, g: G) -> (A, B)
+    where
+        F: FnOnce(Self) -> A,
+        G: FnOnce(Self) -> B,
+        Self: Clone;
+}
+
+impl<T: ?Sized> Fork for T {
+    fn fork<Fs>(self, fs: Fs) -> Fs::Output
+    where
+        Fs: Forks<Self>,
+        Self: Sized,
+    {
+        fs.fork(self)
+    }
+
+    fn fork_ref<Fs>(&self, fs: Fs) -> Fs::Output
+    where
+        Fs: ForksRef<Self>,
+    {
+        fs.fork_ref(self)
+    }
+
+    fn apply_both<F, G, A, B>(self, f: F, g: G) -> (A, B)
+    where
+        F: FnOnce(Self) -> A,
+        G: FnOnce(Self) -> B,
+        Self: Clone,
+    {
+        (f(self.clone()), g(self))
+    }
+}
+
+/// Tuples of functions that can be given to [`Fork::fork`].
+pub trait Forks<T> {
+    /// The tuple of results.
+    type Output;
+
+    /// Apply each function to a clone of the value.
+    fn fork(self, value: T) -> Self::Output;
+}
+
+/// Tuples of functions that can be given to [`Fork::fork_ref`].
+pub trait ForksRef<T: ?Sized> {
+    /// The tuple of results.
+    type Output;
+
+    /// Apply each function to a reference to the value.
+    fn fork_ref(self, value: &T) -> Self::Output;
+}
+
+macro_rules! forks {
+    ($($F:ident $U:ident),* ; $L:ident $V:ident) => {
+        impl<T: Clone, $($F, $U,)* $L, $V> Forks<T> for ($($F,)* $L,)
+        where
+            $($F: FnOnce(T) -> $U,)*
+            $L: FnOnce(T) -> $V,
+        {
+            type Output = ($($U,)* $V,);
+
+            #[allow(non_snake_case)]
+            fn fork(self, value: T) -> Self::Output {
+                let ($($F,)* $L,) = self;
+                ($($F(value.clone()),)* $L(value),)
+            }
+        }
+
+        impl<T: ?Sized, $($F, $U,)* $L, $V> ForksRef<T> for ($($F,)* $L,)
+        where
+            $($F: FnOnce(&T) -> $U,)*
+            $L: FnOnce(&T) -> $V,
+        {
+            type Output = ($($U,)* $V,);
+
+            #[allow(non_snake_case)]
+            fn fork_ref(self, value: &T) -> Self::Output {
+                let ($($F,)* $L,) = self;
+                ($($F(value),)* $L(value),)
+            }
+        }
+    };
+}
+
+forks!(; F1 U1);
+forks!(F1 U1; F2 U2);
+forks!(F1 U1, F2 U2; F3 U3);
+forks!(F1 U1, F2 U2, F3 U3; F4 U4);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4; F5 U5);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4, F5 U5; F6 U6);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4, F5 U5, F6 U6; F7 U7);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4, F5 U5, F6 U6, F7 U7; F8 U8);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4, F5 U5, F6 U6, F7 U7, F8 U8; F9 U9);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4, F5 U5, F6 U6, F7 U7, F8 U8, F9 U9; F10 U10);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4, F5 U5, F6 U6, F7 U7, F8 U8, F9 U9, F10 U10; F11 U11);
+forks!(F1 U1, F2 U2, F3 U3, F4 U4, F5 U5, F6 U6, F7 U7, F8 U8, F9 U9, F10 U10, F11 U11; F12 U12);
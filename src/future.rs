@@ -0,0 +1,88 @@
+//! Application in `async` code.
+
+use core::future::Future;
+use core::ops::AsyncFnOnce;
+
+/// Apply `async` functions in method-position.
+///
+/// ```
+/// use applying::AsyncApply;
+/// # use std::future::Future;
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+/// # fn block_on<F: Future>(fut: F) -> F::Output {
+/// #     let mut fut = pin!(fut);
+/// #     let mut cx = Context::from_waker(Waker::noop());
+/// #     loop {
+/// #         if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+/// #             return v;
+/// #         }
+/// #     }
+/// # }
+///
+/// async fn double(n: u32) -> u32 {
+///     n * 2
+/// }
+///
+/// let n = block_on(async { 2.apply_async(double).await.apply_async(double).await });
+/// assert_eq!(8, n);
+/// ```
+pub trait AsyncApply: Sized {
+    /// Apply a given `async` function in method-position.
+    fn apply_async<F, U>(self, f: F) -> impl Future<Output = U>
+    where
+        F: AsyncFnOnce(Self) -> U;
+}
+
+impl<T> AsyncApply for T {
+    fn apply_async<F, U>(self, f: F) -> impl Future<Output = U>
+    where
+        F: AsyncFnOnce(Self) -> U,
+    {
+        f(self)
+    }
+}
+
+/// Apply functions to the output of a [`Future`], before it has been awaited.
+///
+/// Nothing is run until the resulting future is polled, so runtime-specific
+/// behaviour like spawning is left entirely to the caller.
+///
+/// ```
+/// use applying::FutureApply;
+/// use std::sync::Arc;
+/// # use std::future::Future;
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+/// # fn block_on<F: Future>(fut: F) -> F::Output {
+/// #     let mut fut = pin!(fut);
+/// #     let mut cx = Context::from_waker(Waker::noop());
+/// #     loop {
+/// #         if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+/// #             return v;
+/// #         }
+/// #     }
+/// # }
+///
+/// async fn fetch_user() -> String {
+///     "Colin".to_string()
+/// }
+///
+/// let user = block_on(fetch_user().apply_output(Arc::new));
+/// assert_eq!("Colin", *user);
+/// ```
+pub trait FutureApply: Future + Sized {
+    /// Apply a function to the output of this future once it completes.
+    fn apply_output<F, U>(self, f: F) -> impl Future<Output = U>
+    where
+        F: FnOnce(Self::Output) -> U;
+}
+
+impl<T: Future> FutureApply for T {
+    async fn apply_output<F, U>(self, f: F) -> U
+    where
+        F: FnOnce(Self::Output) -> U,
+    {
+        f(self.await)
+    }
+}
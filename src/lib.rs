@@ -4,7 +4,8 @@
 //!
 //! Much functionality in Rust is provided by methods, but occasionally we're
 //! forced to use certain standalone functions. Examples are functions like
-//! [`std::str::from_utf8`] or [`std::sync::Arc::new`]:
+//! [`std::str::from_utf8`](https://doc.rust-lang.org/std/str/fn.from_utf8.html)
+//! or [`std::sync::Arc::new`](https://doc.rust-lang.org/std/sync/struct.Arc.html#method.new):
 //!
 //! ```ignore
 //! let user = fetch_user()
@@ -51,9 +52,94 @@
 //!
 //! Ah, beautiful, consistent nesting. And no spurrious names to confuse the
 //! peasantry.
+//!
+//! # Features
+//!
+//! This crate is `no_std` by default. Helpers which need an allocator or the
+//! standard library are gated behind the following features:
+//!
+//! - `alloc`: Helpers that need `Box`, `Rc`, `Arc`, and so on.
+//! - `std`: Helpers that need threads, I/O, or timing, like `TimedApply` and
+//!   `Dbg`. Implies `alloc`.
+//! - `async`: The `AsyncApply` and `FutureApply` traits. Requires Rust 1.85.
+//! - `no-dbg`: Silences `Dbg` and `apply_dbg!`, even in debug builds.
+//! - `anyhow`, `eyre`: The `AnyhowApply` and `EyreApply` traits, for attaching
+//!   error context to each stage of a chain. Imply `std`.
+//! - `serde`: Deserialization of a `PipelineConfig`. Implies `alloc`.
+//! - `tracing`: The `TracedApply` trait, for instrumenting each stage of a
+//!   chain with a [`tracing`](https://docs.rs/tracing) span. Implies `std`.
 
+#![no_std]
 #![deny(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod compose;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+mod context;
+mod contract;
+mod conv;
+#[cfg(feature = "std")]
+mod debug;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod dynamic;
+mod fallible;
+mod fork;
+#[cfg(feature = "async")]
+mod future;
+mod pipe;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod pipeline;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod registry;
+pub mod scope;
+mod spread;
+mod stage;
+mod tap;
+#[cfg(feature = "std")]
+mod timed;
+#[cfg(feature = "tracing")]
+mod traced;
+#[cfg(feature = "alloc")]
+mod validate;
+mod wrap;
+
+pub use compose::{compose, Compose};
+#[cfg(feature = "anyhow")]
+pub use context::AnyhowApply;
+#[cfg(feature = "eyre")]
+pub use context::{EyreApply, EyreContext};
+pub use contract::{Contract, ContractViolation, Violation};
+pub use conv::{Conv, ConvError};
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use debug::print as __dbg_print;
+#[cfg(feature = "std")]
+pub use debug::Dbg;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use dynamic::{DynPipeline, DynPipelineError, DynStage};
+pub use fallible::{ApplyOption, ApplyResult};
+pub use fork::{Fork, Forks, ForksRef};
+#[cfg(feature = "async")]
+pub use future::{AsyncApply, FutureApply};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use pipeline::Pipeline;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use registry::{PipelineConfig, Registry, RegistryError};
+pub use spread::Spread;
+pub use stage::StageError;
+pub use tap::Tap;
+#[cfg(feature = "std")]
+pub use timed::{TimedApply, TimingSummary, Timings};
+#[cfg(feature = "tracing")]
+pub use traced::TracedApply;
+#[cfg(feature = "alloc")]
+pub use validate::{NonEmpty, Validate, Validated};
+pub use wrap::Wrap;
+
 /// Apply functions in method-position.
 ///
 /// See the module documentation for more information.
@@ -63,9 +149,110 @@ pub trait Apply {
     where
         F: FnOnce(Self) -> U,
         Self: Sized;
+
+    /// Apply a function that borrows its argument.
+    ///
+    /// Useful for functions that only need a `&T`, without having to bind the
+    /// value to a name first. Unlike [`Apply::apply`], this also works on
+    /// unsized types like `str`, slices, and trait objects:
+    ///
+    /// ```
+    /// use applying::Apply;
+    ///
+    /// fn total(xs: &Vec<u32>) -> u32 {
+    ///     xs.iter().sum()
+    /// }
+    ///
+    /// let sum = vec![1, 2, 3].apply_ref(total);
+    /// assert_eq!(6, sum);
+    ///
+    /// let len = "abc".apply_ref(str::len);
+    /// assert_eq!(3, len);
+    ///
+    /// let bytes: &[u8] = &[1, 2, 3];
+    /// let ascii = bytes.apply_ref(<[u8]>::is_ascii);
+    /// assert!(ascii);
+    ///
+    /// let err: Box<dyn std::error::Error> = "oh no".into();
+    /// let msg = err.as_ref().apply_ref(ToString::to_string);
+    /// assert_eq!("oh no", msg);
+    /// ```
+    fn apply_ref<F, U>(&self, f: F) -> U
+    where
+        F: FnOnce(&Self) -> U;
+
+    /// Apply a function that mutably borrows its argument.
+    ///
+    /// ```
+    /// use applying::Apply;
+    ///
+    /// fn bump(xs: &mut Vec<u32>) -> usize {
+    ///     xs.push(4);
+    ///     xs.len()
+    /// }
+    ///
+    /// let mut xs = vec![1, 2, 3];
+    /// let len = xs.apply_mut(bump);
+    /// assert_eq!(4, len);
+    /// assert_eq!(vec![1, 2, 3, 4], xs);
+    ///
+    /// let mut bytes = [3u8, 1, 2];
+    /// bytes[..].apply_mut(<[u8]>::sort);
+    /// assert_eq!([1, 2, 3], bytes);
+    /// ```
+    fn apply_mut<F, U>(&mut self, f: F) -> U
+    where
+        F: FnOnce(&mut Self) -> U;
+
+    /// Apply a function only if the given condition is `true`.
+    ///
+    /// Otherwise the value is passed through untouched.
+    ///
+    /// ```
+    /// use applying::Apply;
+    ///
+    /// let normalize = true;
+    /// let name = "  Colin ".to_string()
+    ///     .apply_if(normalize, |s| s.trim().to_lowercase());
+    ///
+    /// assert_eq!("colin", name);
+    /// ```
+    fn apply_if<F>(self, cond: bool, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        Self: Sized;
+
+    /// Apply a function only if the value satisfies the given predicate.
+    ///
+    /// ```
+    /// use applying::Apply;
+    ///
+    /// let n = 7.apply_when(|n| n % 2 == 1, |n| n + 1);
+    /// assert_eq!(8, n);
+    /// ```
+    fn apply_when<P, F>(self, pred: P, f: F) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+        F: FnOnce(Self) -> Self,
+        Self: Sized;
+
+    /// Apply a function only if the value does _not_ satisfy the given
+    /// predicate.
+    ///
+    /// ```
+    /// use applying::Apply;
+    ///
+    /// let path = "docs".to_string().apply_unless(|p| p.ends_with('/'), |p| p + "/");
+    /// assert_eq!("docs/", path);
+    /// ```
+    fn apply_unless<P, F>(self, pred: P, f: F) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+        F: FnOnce(Self) -> Self,
+        Self: Sized;
 }
 
-impl<T> Apply for T {
+impl<T: ?Sized> Apply for T {
     fn apply<F, U>(self, f: F) -> U
     where
         F: FnOnce(Self) -> U,
@@ -73,4 +260,50 @@ impl<T> Apply for T {
     {
         f(self)
     }
+
+    fn apply_ref<F, U>(&self, f: F) -> U
+    where
+        F: FnOnce(&Self) -> U,
+    {
+        f(self)
+    }
+
+    fn apply_mut<F, U>(&mut self, f: F) -> U
+    where
+        F: FnOnce(&mut Self) -> U,
+    {
+        f(self)
+    }
+
+    fn apply_if<F>(self, cond: bool, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+        Self: Sized,
+    {
+        if cond {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    fn apply_when<P, F>(self, pred: P, f: F) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+        F: FnOnce(Self) -> Self,
+        Self: Sized,
+    {
+        let cond = pred(&self);
+        self.apply_if(cond, f)
+    }
+
+    fn apply_unless<P, F>(self, pred: P, f: F) -> Self
+    where
+        P: FnOnce(&Self) -> bool,
+        F: FnOnce(Self) -> Self,
+        Self: Sized,
+    {
+        let cond = !pred(&self);
+        self.apply_if(cond, f)
+    }
 }
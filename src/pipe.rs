@@ -0,0 +1,226 @@
+//! Pipelines of function calls via macros.
+
+/// Pipe a value through a series of functions.
+///
+/// Each stage after a `=>` is either a function to call with the value, or a
+/// call expression in which `_` marks where the value should go. Everything
+/// expands to plain function calls, so there is no runtime overhead.
+///
+/// ```
+/// use applying::pipe;
+/// use std::sync::Arc;
+///
+/// fn scale(n: u32, by: u32, unit: &str) -> String {
+///     format!("{}{}", n * by, unit)
+/// }
+///
+/// let s = pipe!(21 => scale(_, 2, "cm") => String::into_boxed_str => Arc::new);
+/// assert_eq!("42cm", &**s);
+/// ```
+///
+/// The value is evaluated once, even if `_` appears more than once:
+///
+/// ```
+/// use applying::pipe;
+///
+/// let n = pipe!(3 => u32::pow(_, 2) => u32::max(_, _));
+/// assert_eq!(9, n);
+/// ```
+///
+/// Paths may carry generics on any segment, and may be qualified:
+///
+/// ```
+/// use applying::pipe;
+/// use std::ops::Add;
+///
+/// let v = pipe!(3usize => Vec::<u8>::with_capacity(_));
+/// assert!(v.capacity() >= 3);
+///
+/// let n = pipe!(b"abc".as_slice() => <[u8]>::len(_) => <usize as Add>::add(_, 1));
+/// assert_eq!(4, n);
+///
+/// let s = pipe!("a,b" => str::split::<char>(_, ',') => Iterator::collect::<Vec<_>>);
+/// assert_eq!(vec!["a", "b"], s);
+/// ```
+///
+/// Closures and other expressions also work as stages:
+///
+/// ```
+/// use applying::pipe;
+///
+/// let pair = pipe!(1 => |n| n + 1 => |n| (n, n));
+/// assert_eq!((2, 2), pair);
+/// ```
+///
+/// Each stage is expanded separately, so long pipelines are fine:
+///
+/// ```
+/// use applying::pipe;
+///
+/// let n = pipe!(
+///     0u32 => u32::saturating_add(_, 1) => u32::saturating_add(_, 1)
+///          => u32::saturating_add(_, 1) => u32::saturating_add(_, 1)
+///          => u32::saturating_add(_, 1) => u32::saturating_add(_, 1)
+///          => u32::saturating_add(_, 1) => u32::saturating_add(_, 1)
+///          => u32::saturating_add(_, 1) => u32::saturating_add(_, 1)
+///          => u32::saturating_add(_, 1) => u32::saturating_add(_, 1)
+///          => u32::pow(_, 2) => |n| n + 1
+/// );
+/// assert_eq!(145, n);
+/// ```
+///
+/// See [`pipe_try!`](crate::pipe_try!) for pipelines of fallible functions.
+#[macro_export]
+macro_rules! pipe {
+    // A path, called with an argument list that may contain `_`.
+    (@stages $mode:tt $v:ident ;
+     $(<$t:ty $(as $tr:path)?>::)? $($f:ident $(::<$($g:ty),+>)?)::+ ($($args:tt)*)
+     $(? $(($($conv:tt)*))?)?
+     $(=> $($rest:tt)+)?) => {{
+        let $v = $crate::pipe!(@suffix $mode [$(? $(($($conv)*))?)?]
+            $crate::pipe!(@args $v
+                [$(<$t $(as $tr)?>::)? $($f $(::<$($g),+>)?)::+]
+                [$($args)*] [] [] $($args)*));
+        $crate::pipe!(@stages $mode $v ; $($($rest)+)?)
+    }};
+    // A path to a function.
+    (@stages $mode:tt $v:ident ;
+     $(<$t:ty $(as $tr:path)?>::)? $($f:ident $(::<$($g:ty),+>)?)::+
+     $(? $(($($conv:tt)*))?)?
+     $(=> $($rest:tt)+)?) => {{
+        let $v = $crate::pipe!(@suffix $mode [$(? $(($($conv)*))?)?]
+            $(<$t $(as $tr)?>::)? $($f $(::<$($g),+>)?)::+($v));
+        $crate::pipe!(@stages $mode $v ; $($($rest)+)?)
+    }};
+    // A parenthesised expression which yields a function.
+    (@stages $mode:tt $v:ident ;
+     ($($e:tt)*)
+     $(? $(($($conv:tt)*))?)?
+     $(=> $($rest:tt)+)?) => {{
+        let $v = $crate::pipe!(@suffix $mode [$(? $(($($conv)*))?)?] ($($e)*)($v));
+        $crate::pipe!(@stages $mode $v ; $($($rest)+)?)
+    }};
+    // Any other expression which yields a function, like a closure.
+    (@stages $mode:tt $v:ident ; $e:expr $(=> $($rest:tt)+)?) => {{
+        let $v = $crate::pipe!(@suffix $mode [] ($e)($v));
+        $crate::pipe!(@stages $mode $v ; $($($rest)+)?)
+    }};
+    (@stages $mode:tt $v:ident ;) => {
+        $v
+    };
+
+    // Apply a stage's `?` or `?(conv)` marker, if any.
+    (@suffix $mode:tt [? ($($conv:tt)*)] $e:expr) => {
+        $e.map_err($($conv)*)?
+    };
+    (@suffix $mode:tt [?] $e:expr) => {
+        $e?
+    };
+    (@suffix [try] [] $e:expr) => {
+        $e?
+    };
+    (@suffix [plain] [] $e:expr) => {
+        $e
+    };
+
+    // Substitute the value for each `_` in an argument list.
+    (@args $v:ident [$($head:tt)*] $orig:tt [$($acc:tt)*] [found]) => {
+        $($head)*($($acc)*)
+    };
+    (@args $v:ident [$($head:tt)*] [$($orig:tt)*] $acc:tt []) => {
+        ($($head)*($($orig)*))($v)
+    };
+    (@args $v:ident $head:tt $orig:tt [$($acc:tt)*] $found:tt _ $(, $($rest:tt)*)?) => {
+        $crate::pipe!(@args $v $head $orig [$($acc)* $v,] [found] $($($rest)*)?)
+    };
+    (@args $v:ident $head:tt $orig:tt [$($acc:tt)*] $found:tt $arg:expr $(, $($rest:tt)*)?) => {
+        $crate::pipe!(@args $v $head $orig [$($acc)* $arg,] $found $($($rest)*)?)
+    };
+
+    ($init:expr $(=> $($stages:tt)+)?) => {{
+        let value = $init;
+        $crate::pipe!(@stages [plain] value ; $($($stages)+)?)
+    }};
+}
+
+/// Pipe a value through a series of fallible functions.
+///
+/// Works like [`pipe!`], except that a `?` is inserted after every stage, so
+/// the surrounding function returns early on the first failure. This works in
+/// functions that return either `Result` or `Option`.
+///
+/// ```
+/// use applying::pipe_try;
+///
+/// fn halve(n: u32) -> Option<u32> {
+///     (n % 2 == 0).then_some(n / 2)
+/// }
+///
+/// fn quarter(n: u32) -> Option<u32> {
+///     Some(pipe_try!(n => halve => halve))
+/// }
+///
+/// assert_eq!(Some(3), quarter(12));
+/// assert_eq!(None, quarter(6));
+/// ```
+///
+/// Following a stage with `?(f)` converts its error with `f` before returning
+/// it:
+///
+/// ```
+/// use applying::pipe_try;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// enum Error {
+///     Parse(ParseIntError),
+///     TooBig(u32),
+/// }
+///
+/// fn small(n: u32) -> Result<u32, Error> {
+///     if n < 100 { Ok(n) } else { Err(Error::TooBig(n)) }
+/// }
+///
+/// fn parse(s: &str) -> Result<u32, Error> {
+///     Ok(pipe_try!(s => str::parse::<u32>(_)?(Error::Parse) => small))
+/// }
+///
+/// assert_eq!(42, parse("42").unwrap());
+/// assert!(matches!(parse("x"), Err(Error::Parse(_))));
+/// assert!(matches!(parse("500"), Err(Error::TooBig(500))));
+/// ```
+///
+/// To only mark _some_ stages as fallible, use [`pipe!`] and follow those
+/// stages with `?` or `?(f)` yourself. Markers are recognised as part of each
+/// stage, so they don't cost anything extra in long pipelines. Closures must
+/// be parenthesised to take a marker:
+///
+/// ```
+/// use applying::pipe;
+///
+/// fn ones(s: &str) -> Result<u32, std::num::ParseIntError> {
+///     Ok(pipe!(s => str::trim => str::parse::<u32>? => u32::count_ones))
+/// }
+///
+/// fn countdown(n: u32) -> Option<u32> {
+///     Some(pipe!(
+///         n => u32::checked_sub(_, 1)? => u32::checked_sub(_, 1)?
+///           => u32::checked_sub(_, 1)? => u32::checked_sub(_, 1)?
+///           => u32::checked_sub(_, 1)? => u32::checked_sub(_, 1)?
+///           => u32::checked_sub(_, 1)? => u32::checked_sub(_, 1)?
+///           => u32::checked_sub(_, 1)? => u32::checked_sub(_, 1)?
+///           => (|n: u32| n.checked_sub(1))? => |n| n * 2
+///     ))
+/// }
+///
+/// assert_eq!(3, ones(" 7 ").unwrap());
+/// assert_eq!(Some(2), countdown(12));
+/// assert_eq!(None, countdown(10));
+/// ```
+#[macro_export]
+macro_rules! pipe_try {
+    ($init:expr $(=> $($stages:tt)+)?) => {{
+        let value = $init;
+        $crate::pipe!(@stages [try] value ; $($($stages)+)?)
+    }};
+}
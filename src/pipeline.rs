@@ -0,0 +1,193 @@
+//! Reusable pipelines of functions.
+
+use crate::StageError;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A chain of functions, built once and run many times.
+///
+/// Where [`Apply::apply`](crate::Apply::apply) runs a function immediately, a
+/// `Pipeline` describes a series of stages to run later. Pipelines are cheap
+/// to clone and can be shared between threads.
+///
+/// ```
+/// use applying::{Apply, Pipeline};
+///
+/// let normalize = Pipeline::new()
+///     .then_named("trim", |s: String| s.trim().to_string())
+///     .then_named("lower", |s| s.to_lowercase())
+///     .then(|s| s.len());
+///
+/// assert_eq!(5, normalize.run("  HeLLo ".to_string()));
+/// assert_eq!(3, "Abc".to_string().apply(normalize.as_fn()));
+/// assert_eq!(&[Some("trim"), Some("lower"), None], normalize.stages());
+///
+/// let f = normalize.into_fn();
+/// assert_eq!(2, "Hi".to_string().apply(f.clone()));
+/// ```
+///
+/// Since the `Fn*` traits can't be implemented by hand on stable Rust, a
+/// `Pipeline` is turned into a function with [`Pipeline::as_fn`] or
+/// [`Pipeline::into_fn`] before being passed to `apply`.
+pub struct Pipeline<T, U> {
+    run: Arc<dyn Fn(T) -> U + Send + Sync>,
+    stages: Vec<Option<&'static str>>,
+}
+
+impl<T: 'static> Pipeline<T, T> {
+    /// An empty pipeline, which yields its input untouched.
+    pub fn new() -> Self {
+        Pipeline {
+            run: Arc::new(|t| t),
+            stages: Vec::new(),
+        }
+    }
+}
+
+impl<T: 'static> Default for Pipeline<T, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static, U: 'static> Pipeline<T, U> {
+    /// Add a stage to the end of the pipeline.
+    pub fn then<F, V>(self, f: F) -> Pipeline<T, V>
+    where
+        F: Fn(U) -> V + Send + Sync + 'static,
+    {
+        self.push(None, f)
+    }
+
+    /// Add a named stage to the end of the pipeline.
+    pub fn then_named<F, V>(self, name: &'static str, f: F) -> Pipeline<T, V>
+    where
+        F: Fn(U) -> V + Send + Sync + 'static,
+    {
+        self.push(Some(name), f)
+    }
+
+    /// Add a named, fallible stage to the end of the pipeline.
+    ///
+    /// Should the stage fail, its error is wrapped in a [`StageError`] that
+    /// records the stage's name and position. Further fallible stages can be
+    /// added with [`Pipeline::and_then`].
+    ///
+    /// ```
+    /// use applying::{Pipeline, StageError};
+    ///
+    /// let p = Pipeline::new()
+    ///     .then_named("trim", |s: String| s.trim().to_string())
+    ///     .try_then("parse", |s| s.parse::<i32>().map_err(|e| e.to_string()))
+    ///     .and_then("positive", |n| u32::try_from(n).map_err(|e| e.to_string()));
+    ///
+    /// assert_eq!(Ok(7), p.run(" 7 ".to_string()));
+    ///
+    /// let e = p.run("x".to_string()).unwrap_err();
+    /// assert_eq!(("parse", 1), (e.stage, e.index));
+    ///
+    /// let e = p.run("-7".to_string()).unwrap_err();
+    /// assert_eq!(("positive", 2), (e.stage, e.index));
+    /// assert_eq!("Stage 2 (positive) failed", e.to_string());
+    /// ```
+    pub fn try_then<F, V, E>(
+        self,
+        name: &'static str,
+        f: F,
+    ) -> Pipeline<T, Result<V, StageError<E>>>
+    where
+        F: Fn(U) -> Result<V, E> + Send + Sync + 'static,
+    {
+        let index = self.len();
+        self.push(Some(name), move |u| {
+            f(u).map_err(|e| StageError::new(name, index, e))
+        })
+    }
+
+    fn push<F, V>(self, name: Option<&'static str>, f: F) -> Pipeline<T, V>
+    where
+        F: Fn(U) -> V + Send + Sync + 'static,
+    {
+        let Pipeline { run, mut stages } = self;
+        stages.push(name);
+
+        Pipeline {
+            run: Arc::new(move |t| f(run(t))),
+            stages,
+        }
+    }
+}
+
+impl<T: 'static, U: 'static, E: 'static> Pipeline<T, Result<U, StageError<E>>> {
+    /// Add another named, fallible stage to the end of the pipeline, which
+    /// only runs if all previous stages succeeded.
+    ///
+    /// As with `?`, the stage's error is converted into the error type of the
+    /// pipeline via [`From`]. See [`Pipeline::try_then`].
+    pub fn and_then<F, V, X>(
+        self,
+        name: &'static str,
+        f: F,
+    ) -> Pipeline<T, Result<V, StageError<E>>>
+    where
+        F: Fn(U) -> Result<V, X> + Send + Sync + 'static,
+        E: From<X>,
+    {
+        let index = self.len();
+        self.push(Some(name), move |r: Result<U, StageError<E>>| {
+            r.and_then(|u| f(u).map_err(|e| StageError::new(name, index, E::from(e))))
+        })
+    }
+}
+
+impl<T, U> Pipeline<T, U> {
+    /// Run the pipeline on the given input.
+    pub fn run(&self, input: T) -> U {
+        (self.run)(input)
+    }
+
+    /// Borrow the pipeline as a function.
+    pub fn as_fn(&self) -> impl Fn(T) -> U + Copy + '_ {
+        move |t| self.run(t)
+    }
+
+    /// Convert the pipeline into a function, which can be cloned as cheaply as
+    /// the pipeline itself.
+    pub fn into_fn(self) -> impl Fn(T) -> U + Clone + Send + Sync {
+        let run = self.run;
+        move |t| run(t)
+    }
+
+    /// The names of each stage, in order. Unnamed stages are `None`.
+    pub fn stages(&self) -> &[Option<&'static str>] {
+        &self.stages
+    }
+
+    /// The number of stages in the pipeline.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Does the pipeline have no stages?
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+impl<T, U> Clone for Pipeline<T, U> {
+    fn clone(&self) -> Self {
+        Pipeline {
+            run: Arc::clone(&self.run),
+            stages: self.stages.clone(),
+        }
+    }
+}
+
+impl<T, U> fmt::Debug for Pipeline<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("stages", &self.stages)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,146 @@
+//! Named stages, assembled into pipelines at runtime.
+
+use crate::{DynPipeline, DynPipelineError, DynStage};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A collection of functions registered under names.
+///
+/// Pipelines can then be assembled from a list of those names, for instance
+/// one read from a config file. This allows stages to be reordered without
+/// recompiling.
+///
+/// ```
+/// use applying::Registry;
+///
+/// let registry = Registry::new()
+///     .register("trim", |s: String| s.trim().to_string())
+///     .register("upper", |s: String| s.to_uppercase())
+///     .register("len", |s: String| s.len());
+///
+/// let pipeline = registry.pipeline(["trim", "upper"]).unwrap();
+/// let s: String = pipeline.run(" hi ".to_string()).unwrap();
+/// assert_eq!("HI", s);
+///
+/// assert!(registry.pipeline(["trim", "shout"]).is_err());
+/// assert!(registry.pipeline(["len", "trim"]).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    stages: BTreeMap<&'static str, DynStage>,
+}
+
+impl Registry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a function under the given name, replacing any function
+    /// previously registered under it.
+    pub fn register<F, A, B>(mut self, name: &'static str, f: F) -> Self
+    where
+        F: Fn(A) -> B + Send + Sync + 'static,
+        A: 'static,
+        B: 'static,
+    {
+        self.insert(DynStage::new(name, f));
+        self
+    }
+
+    /// Register an existing stage under its own name, yielding any stage
+    /// previously registered under it.
+    pub fn insert(&mut self, stage: DynStage) -> Option<DynStage> {
+        self.stages.insert(stage.name(), stage)
+    }
+
+    /// The stage registered under the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&DynStage> {
+        self.stages.get(name)
+    }
+
+    /// The names of all registered stages, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.stages.keys().copied()
+    }
+
+    /// Assemble a pipeline from the stages registered under the given names.
+    pub fn pipeline<I, S>(&self, names: I) -> Result<DynPipeline, RegistryError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let stages = names
+            .into_iter()
+            .map(|name| {
+                let name = name.as_ref();
+                self.get(name)
+                    .cloned()
+                    .ok_or_else(|| RegistryError::UnknownStage(name.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        DynPipeline::new(stages).map_err(RegistryError::Pipeline)
+    }
+
+    /// Assemble a pipeline as described by the given config.
+    pub fn from_config(&self, config: &PipelineConfig) -> Result<DynPipeline, RegistryError> {
+        self.pipeline(&config.stages)
+    }
+}
+
+/// A description of a pipeline, by the names of its stages.
+///
+/// With the `serde` feature, this can be read from any format that `serde`
+/// supports, like TOML or JSON:
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use applying::{PipelineConfig, Registry};
+///
+/// let registry = Registry::new()
+///     .register("parse", |s: String| s.parse::<u32>().unwrap_or(0))
+///     .register("double", |n: u32| n * 2);
+///
+/// let config: PipelineConfig =
+///     serde_json::from_str(r#"{ "stages": ["parse", "double", "double"] }"#).unwrap();
+/// let pipeline = registry.from_config(&config).unwrap();
+///
+/// assert_eq!(Ok(20), pipeline.run::<String, u32>("5".to_string()));
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PipelineConfig {
+    /// The names of each stage, in order.
+    pub stages: Vec<String>,
+}
+
+/// A failure to assemble a pipeline from a [`Registry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// No stage was registered under the given name.
+    UnknownStage(String),
+    /// The named stages don't fit together.
+    Pipeline(DynPipelineError),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::UnknownStage(name) => write!(f, "No stage named {}", name),
+            RegistryError::Pipeline(e) => write!(f, "Invalid pipeline: {}", e),
+        }
+    }
+}
+
+impl core::error::Error for RegistryError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            RegistryError::UnknownStage(_) => None,
+            RegistryError::Pipeline(e) => Some(e),
+        }
+    }
+}
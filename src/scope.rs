@@ -0,0 +1,148 @@
+//! Kotlin-style scope functions.
+//!
+//! For those coming from Kotlin, this module provides its familiar set of
+//! scope functions, built on top of [`Apply`] and [`Tap`]:
+//!
+//! | Kotlin       | Here                    | Receives   | Returns          |
+//! |--------------|-------------------------|------------|------------------|
+//! | `let`        | [`Scope::let_`]         | `T`        | `U`              |
+//! | `also`       | [`Scope::also`]         | `&T`       | `T`              |
+//! | `run`        | [`Scope::run`]          | `&mut T`   | `U`              |
+//! | `with`       | [`with`]                | `T`        | `U`              |
+//! | `takeIf`     | [`Scope::take_if`]      | `&T`       | `Option<T>`      |
+//! | `takeUnless` | [`Scope::take_unless`]  | `&T`       | `Option<T>`      |
+//!
+//! Kotlin's own `apply` would clash with [`Apply::apply`], so use
+//! [`Tap::tap_mut`] instead.
+//!
+//! ```
+//! use applying::scope::{with, Scope};
+//!
+//! let mut log = Vec::new();
+//! let port = "8080"
+//!     .also(|s| log.push(format!("parsing {s}")))
+//!     .let_(str::parse::<u16>)
+//!     .ok()
+//!     .and_then(|p| p.take_unless(|p| *p < 1024));
+//!
+//! assert_eq!(Some(8080), port);
+//! assert_eq!(vec!["parsing 8080"], log);
+//!
+//! let total = with(vec![1, 2, 3], |xs| xs.iter().sum::<u32>());
+//! assert_eq!(6, total);
+//! ```
+
+use crate::{Apply, Tap};
+
+/// Kotlin-style scope functions in method-position.
+///
+/// See the [module documentation](self) for an overview.
+pub trait Scope: Sized {
+    /// Call a function with a reference to the value, then return the value.
+    ///
+    /// The same as [`Tap::tap`].
+    fn also<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self);
+
+    /// Apply a function to the value.
+    ///
+    /// The same as [`Apply::apply`].
+    fn let_<F, U>(self, f: F) -> U
+    where
+        F: FnOnce(Self) -> U;
+
+    /// Call a function with a mutable reference to the value, returning its
+    /// result.
+    ///
+    /// The same as [`Apply::apply_mut`].
+    ///
+    /// ```
+    /// use applying::scope::Scope;
+    ///
+    /// let mut xs = vec![3, 1, 2];
+    /// let first = xs.run(|xs| {
+    ///     xs.sort();
+    ///     xs[0]
+    /// });
+    ///
+    /// assert_eq!(1, first);
+    /// assert_eq!(vec![1, 2, 3], xs);
+    /// ```
+    fn run<F, U>(&mut self, f: F) -> U
+    where
+        F: FnOnce(&mut Self) -> U;
+
+    /// Yield `Some` of the value if it satisfies the predicate, else `None`.
+    ///
+    /// Note that on `Option` this is shadowed by the inherent
+    /// [`Option::take_if`], which instead takes the value out of the option in
+    /// place. Call `Scope::take_if(opt, pred)` to get this version.
+    ///
+    /// ```
+    /// use applying::scope::Scope;
+    ///
+    /// assert_eq!(Some(4), 4.take_if(|n| n % 2 == 0));
+    /// assert_eq!(None, 5.take_if(|n| n % 2 == 0));
+    /// ```
+    fn take_if<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool;
+
+    /// Yield `Some` of the value if it does _not_ satisfy the predicate, else
+    /// `None`.
+    fn take_unless<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool;
+}
+
+impl<T> Scope for T {
+    fn also<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self),
+    {
+        self.tap(f)
+    }
+
+    fn let_<F, U>(self, f: F) -> U
+    where
+        F: FnOnce(Self) -> U,
+    {
+        self.apply(f)
+    }
+
+    fn run<F, U>(&mut self, f: F) -> U
+    where
+        F: FnOnce(&mut Self) -> U,
+    {
+        self.apply_mut(f)
+    }
+
+    fn take_if<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        if pred(&self) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn take_unless<P>(self, pred: P) -> Option<Self>
+    where
+        P: FnOnce(&Self) -> bool,
+    {
+        self.take_if(|t| !pred(t))
+    }
+}
+
+/// Call a function with the given value, returning its result.
+///
+/// The same as [`Apply::apply`], but in prefix position.
+pub fn with<T, F, U>(value: T, f: F) -> U
+where
+    F: FnOnce(T) -> U,
+{
+    f(value)
+}
@@ -0,0 +1,53 @@
+//! Application of multi-argument functions to tuples.
+
+/// Apply a multi-argument function to the elements of a tuple.
+///
+/// Implemented for tuples of up to 12 elements.
+///
+/// ```
+/// use applying::{Apply, Spread};
+///
+/// fn area(w: u32, h: u32) -> u32 {
+///     w * h
+/// }
+///
+/// let a = "3x4"
+///     .split_once('x')
+///     .map(|(w, h)| (w.parse().unwrap(), h.parse().unwrap()))
+///     .unwrap()
+///     .apply_spread(area);
+///
+/// assert_eq!(12, a);
+/// ```
+pub trait Spread<F, U> {
+    /// Apply a given function to the elements of this tuple as separate
+    /// arguments.
+    fn apply_spread(self, f: F) -> U;
+}
+
+macro_rules! spread {
+    ($($T:ident $t:ident),+) => {
+        impl<F, U, $($T),+> Spread<F, U> for ($($T,)+)
+        where
+            F: FnOnce($($T),+) -> U,
+        {
+            fn apply_spread(self, f: F) -> U {
+                let ($($t,)+) = self;
+                f($($t),+)
+            }
+        }
+    };
+}
+
+spread!(A a);
+spread!(A a, B b);
+spread!(A a, B b, C c);
+spread!(A a, B b, C c, D d);
+spread!(A a, B b, C c, D d, E e);
+spread!(A a, B b, C c, D d, E e, G g);
+spread!(A a, B b, C c, D d, E e, G g, H h);
+spread!(A a, B b, C c, D d, E e, G g, H h, I i);
+spread!(A a, B b, C c, D d, E e, G g, H h, I i, J j);
+spread!(A a, B b, C c, D d, E e, G g, H h, I i, J j, K k);
+spread!(A a, B b, C c, D d, E e, G g, H h, I i, J j, K k, L l);
+spread!(A a, B b, C c, D d, E e, G g, H h, I i, J j, K k, L l, M m);
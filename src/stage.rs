@@ -0,0 +1,43 @@
+//! Errors attributed to the stage that produced them.
+
+use core::fmt;
+
+/// An error, along with the pipeline stage it came from.
+///
+/// See [`ApplyResult::try_apply_stage`](crate::ApplyResult::try_apply_stage)
+/// and `Pipeline::try_then` for producing these in a chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageError<E> {
+    /// The name of the stage that failed.
+    pub stage: &'static str,
+    /// The position of that stage in its pipeline.
+    pub index: usize,
+    /// The original error.
+    pub source: E,
+}
+
+impl<E> StageError<E> {
+    /// Attribute an error to the given stage.
+    pub fn new(stage: &'static str, index: usize, source: E) -> Self {
+        StageError {
+            stage,
+            index,
+            source,
+        }
+    }
+}
+
+impl<E> fmt::Display for StageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stage {} ({}) failed", self.index, self.stage)
+    }
+}
+
+impl<E> core::error::Error for StageError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
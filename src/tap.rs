@@ -0,0 +1,85 @@
+//! Side effects in method-position.
+
+/// Perform side effects in the middle of a method chain.
+///
+/// Each method hands the value to a function and then returns it unchanged,
+/// so that logging, assertions, and the like don't force a break in the chain.
+///
+/// ```
+/// use applying::{Apply, Tap};
+///
+/// let mut seen = Vec::new();
+/// let len = String::from("hello")
+///     .tap(|s| seen.push(s.clone()))
+///     .apply(|s| s.len());
+///
+/// assert_eq!(5, len);
+/// assert_eq!(vec!["hello".to_string()], seen);
+/// ```
+pub trait Tap: Sized {
+    /// Call a function with a reference to the value, then return the value.
+    fn tap<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self);
+
+    /// Call a function with a mutable reference to the value, then return the
+    /// value.
+    ///
+    /// ```
+    /// use applying::Tap;
+    ///
+    /// let xs = vec![3, 1, 2].tap_mut(|xs| xs.sort());
+    /// assert_eq!(vec![1, 2, 3], xs);
+    /// ```
+    fn tap_mut<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self);
+
+    /// Like [`Tap::tap`], but only calls the function in debug builds.
+    fn tap_dbg<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self);
+
+    /// Like [`Tap::tap_mut`], but only calls the function in debug builds.
+    fn tap_mut_dbg<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self);
+}
+
+impl<T> Tap for T {
+    fn tap<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self),
+    {
+        f(&self);
+        self
+    }
+
+    fn tap_mut<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        f(&mut self);
+        self
+    }
+
+    fn tap_dbg<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Self),
+    {
+        if cfg!(debug_assertions) {
+            f(&self);
+        }
+        self
+    }
+
+    fn tap_mut_dbg<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        if cfg!(debug_assertions) {
+            f(&mut self);
+        }
+        self
+    }
+}
@@ -0,0 +1,172 @@
+//! Application with timing.
+
+use core::fmt;
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Instant;
+use std::vec::Vec;
+
+/// Apply functions in method-position, measuring how long they take.
+///
+/// ```
+/// use applying::{Apply, TimedApply};
+///
+/// let (sum, elapsed) = (1..=100u32).apply_timed(Iterator::sum::<u32>);
+///
+/// assert_eq!(5050, sum);
+/// assert!(elapsed.as_secs() < 1);
+/// ```
+pub trait TimedApply: Sized {
+    /// Apply a given function, yielding its result and the time it took.
+    fn apply_timed<F, U>(self, f: F) -> (U, Duration)
+    where
+        F: FnOnce(Self) -> U;
+
+    /// Apply a given function, reporting the time it took to `report`.
+    ///
+    /// This keeps the chain intact, and pairs well with [`Timings::recorder`].
+    fn apply_timed_with<F, U, R>(self, f: F, report: R) -> U
+    where
+        F: FnOnce(Self) -> U,
+        R: FnOnce(Duration);
+}
+
+impl<T> TimedApply for T {
+    fn apply_timed<F, U>(self, f: F) -> (U, Duration)
+    where
+        F: FnOnce(Self) -> U,
+    {
+        let start = Instant::now();
+        let output = f(self);
+        (output, start.elapsed())
+    }
+
+    fn apply_timed_with<F, U, R>(self, f: F, report: R) -> U
+    where
+        F: FnOnce(Self) -> U,
+        R: FnOnce(Duration),
+    {
+        let (output, elapsed) = self.apply_timed(f);
+        report(elapsed);
+        output
+    }
+}
+
+/// A collection of timings, grouped by stage name.
+///
+/// Timings can be recorded through a shared reference, so one `Timings` can
+/// be used across an entire run, and even across threads.
+///
+/// ```
+/// use applying::{TimedApply, Timings};
+///
+/// let timings = Timings::new();
+///
+/// for s in ["1", "22", "333"] {
+///     let n = s
+///         .apply_timed_with(str::parse::<u32>, timings.recorder("parse"))
+///         .unwrap()
+///         .apply_timed_with(|n| n * 2, timings.recorder("double"));
+///     assert!(n > 0);
+/// }
+///
+/// let summary = timings.summary();
+/// assert_eq!(2, summary.len());
+/// assert_eq!(("double", 3), (summary[0].0, summary[0].1.count));
+///
+/// // One line per stage, with its min/mean/max.
+/// println!("{timings}");
+/// ```
+#[derive(Debug, Default)]
+pub struct Timings {
+    stages: Mutex<BTreeMap<&'static str, TimingSummary>>,
+}
+
+impl Timings {
+    /// An empty set of timings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a single timing for the given stage.
+    pub fn record(&self, stage: &'static str, elapsed: Duration) {
+        let mut stages = self.stages.lock().unwrap_or_else(PoisonError::into_inner);
+
+        stages
+            .entry(stage)
+            .and_modify(|s| s.add(elapsed))
+            .or_insert_with(|| TimingSummary::new(elapsed));
+    }
+
+    /// A function that records a timing for the given stage, suitable for
+    /// passing to [`TimedApply::apply_timed_with`].
+    pub fn recorder(&self, stage: &'static str) -> impl FnOnce(Duration) + '_ {
+        move |elapsed| self.record(stage, elapsed)
+    }
+
+    /// The summarized timings of each stage, ordered by stage name.
+    pub fn summary(&self) -> Vec<(&'static str, TimingSummary)> {
+        self.stages
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(stage, summary)| (*stage, *summary))
+            .collect()
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (stage, s) in self.summary() {
+            writeln!(
+                f,
+                "{}: n={} min={:?} mean={:?} max={:?}",
+                stage,
+                s.count,
+                s.min,
+                s.mean(),
+                s.max
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Summarized timings of a single stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimingSummary {
+    /// The number of timings recorded.
+    pub count: u64,
+    /// The sum of all timings recorded.
+    pub total: Duration,
+    /// The shortest timing recorded.
+    pub min: Duration,
+    /// The longest timing recorded.
+    pub max: Duration,
+}
+
+impl TimingSummary {
+    fn new(elapsed: Duration) -> Self {
+        TimingSummary {
+            count: 1,
+            total: elapsed,
+            min: elapsed,
+            max: elapsed,
+        }
+    }
+
+    fn add(&mut self, elapsed: Duration) {
+        self.count = self.count.saturating_add(1);
+        self.total = self.total.saturating_add(elapsed);
+        self.min = self.min.min(elapsed);
+        self.max = self.max.max(elapsed);
+    }
+
+    /// The mean of all timings recorded.
+    pub fn mean(&self) -> Duration {
+        let nanos = self.total.as_nanos() / u128::from(self.count);
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
@@ -0,0 +1,180 @@
+//! Application with `tracing` instrumentation.
+
+use core::fmt::Debug;
+use std::time::Instant;
+use tracing::field::{debug, Empty};
+
+/// Apply functions in method-position, within a [`tracing`] span.
+///
+/// ```
+/// use applying::TracedApply;
+///
+/// let n = "42"
+///     .apply_traced("parse", str::parse::<u32>)
+///     .unwrap()
+///     .apply_traced("double", |n| n * 2);
+///
+/// assert_eq!(84, n);
+/// ```
+pub trait TracedApply: Sized {
+    /// Apply a given function within a `DEBUG` span named `apply`.
+    ///
+    /// The span records the given `stage` name and the time the call took. If
+    /// the span is enabled, the input and output values are also recorded via
+    /// their [`Debug`] implementations.
+    fn apply_traced<F, U>(self, stage: &str, f: F) -> U
+    where
+        F: FnOnce(Self) -> U,
+        Self: Debug,
+        U: Debug;
+}
+
+impl<T> TracedApply for T {
+    fn apply_traced<F, U>(self, stage: &str, f: F) -> U
+    where
+        F: FnOnce(Self) -> U,
+        Self: Debug,
+        U: Debug,
+    {
+        let span = tracing::debug_span!(
+            "apply",
+            stage,
+            input = Empty,
+            output = Empty,
+            elapsed = Empty
+        );
+        let _guard = span.enter();
+        let enabled = !span.is_disabled();
+
+        if enabled {
+            span.record("input", debug(&self));
+        }
+
+        let start = Instant::now();
+        let output = f(self);
+        let elapsed = start.elapsed();
+
+        if enabled {
+            span.record("output", debug(&output));
+            span.record("elapsed", debug(elapsed));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::fmt;
+    use std::string::String;
+    use std::sync::{Arc, Mutex};
+    use std::vec::Vec;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::subscriber::{with_default, Interest};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Records the name and value of every span field it sees.
+    struct Capture {
+        enabled: bool,
+        fields: Mutex<Vec<(&'static str, String)>>,
+    }
+
+    impl Capture {
+        fn new(enabled: bool) -> Arc<Self> {
+            Arc::new(Capture {
+                enabled,
+                fields: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn names(&self) -> Vec<&'static str> {
+            self.fields
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(n, _)| *n)
+                .collect()
+        }
+    }
+
+    impl Visit for &Capture {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            let value = std::format!("{:?}", value);
+            self.fields.lock().unwrap().push((field.name(), value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+            // Other tests may install their own subscribers concurrently, so
+            // always ask `enabled` rather than caching an answer.
+            Interest::sometimes()
+        }
+
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            self.enabled
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut { self });
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut { self });
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// Counts how many times it has been formatted.
+    struct Counted<'a>(&'a Cell<u32>);
+
+    impl Debug for Counted<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            f.write_str("Counted")
+        }
+    }
+
+    #[test]
+    fn enabled() {
+        let capture = Capture::new(true);
+        let count = Cell::new(0);
+
+        with_default(capture.clone(), || {
+            Counted(&count).apply_traced("stage", |c| c);
+        });
+
+        assert_eq!(2, count.get());
+        assert_eq!(
+            ["stage", "input", "output", "elapsed"].as_slice(),
+            capture.names()
+        );
+
+        let fields = capture.fields.lock().unwrap();
+        assert_eq!("\"stage\"", fields[0].1);
+        assert_eq!("Counted", fields[1].1);
+        assert_eq!("Counted", fields[2].1);
+    }
+
+    #[test]
+    fn disabled() {
+        let capture = Capture::new(false);
+        let count = Cell::new(0);
+
+        let n = with_default(capture.clone(), || {
+            Counted(&count).apply_traced("stage", |_| 1)
+        });
+
+        assert_eq!(1, n);
+        assert_eq!(0, count.get());
+        assert!(capture.names().is_empty());
+    }
+}
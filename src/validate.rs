@@ -0,0 +1,141 @@
+//! Validation that accumulates every error.
+
+use alloc::vec::Vec;
+
+/// Run many checks on a value, collecting all of their errors.
+///
+/// Where a chain of `?` stops at the first failure, `apply_validate` runs
+/// every check, which is what we want when reporting problems with a form or
+/// config file.
+///
+/// ```
+/// use applying::{Validate, Validated};
+///
+/// fn not_empty(s: &&str) -> Result<(), &'static str> {
+///     if s.is_empty() { Err("empty") } else { Ok(()) }
+/// }
+///
+/// fn short(s: &&str) -> Result<(), &'static str> {
+///     if s.len() > 8 { Err("too long") } else { Ok(()) }
+/// }
+///
+/// fn lowercase(s: &&str) -> Result<(), &'static str> {
+///     if s.chars().any(char::is_uppercase) { Err("not lowercase") } else { Ok(()) }
+/// }
+///
+/// let checks = [not_empty, short, lowercase];
+///
+/// assert_eq!(Validated::Valid("colin"), "colin".apply_validate(checks));
+///
+/// let errors = "ColinWoodbury".apply_validate(checks).into_result().unwrap_err();
+/// assert_eq!(vec!["too long", "not lowercase"], errors.into_vec());
+/// ```
+pub trait Validate: Sized {
+    /// Run each check against this value, passing it through only if all of
+    /// them succeed.
+    fn apply_validate<I, F, E>(self, checks: I) -> Validated<Self, E>
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce(&Self) -> Result<(), E>;
+}
+
+impl<T> Validate for T {
+    fn apply_validate<I, F, E>(self, checks: I) -> Validated<Self, E>
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce(&Self) -> Result<(), E>,
+    {
+        let mut errors = checks.into_iter().filter_map(|check| check(&self).err());
+
+        match errors.next() {
+            None => Validated::Valid(self),
+            Some(head) => Validated::Invalid(NonEmpty {
+                head,
+                tail: errors.collect(),
+            }),
+        }
+    }
+}
+
+/// The result of [`Validate::apply_validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validated<T, E> {
+    /// All checks passed.
+    Valid(T),
+    /// At least one check failed.
+    Invalid(NonEmpty<E>),
+}
+
+impl<T, E> Validated<T, E> {
+    /// Did all checks pass?
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Validated::Valid(_))
+    }
+
+    /// Apply a function to the valid value, leaving errors untouched.
+    pub fn map<F, U>(self, f: F) -> Validated<U, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Validated::Valid(t) => Validated::Valid(f(t)),
+            Validated::Invalid(es) => Validated::Invalid(es),
+        }
+    }
+
+    /// Convert into a [`Result`], for use with `?`.
+    pub fn into_result(self) -> Result<T, NonEmpty<E>> {
+        self.into()
+    }
+}
+
+impl<T, E> From<Validated<T, E>> for Result<T, NonEmpty<E>> {
+    fn from(v: Validated<T, E>) -> Self {
+        match v {
+            Validated::Valid(t) => Ok(t),
+            Validated::Invalid(es) => Err(es),
+        }
+    }
+}
+
+/// A [`Vec`] with at least one element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonEmpty<E> {
+    /// The first element.
+    pub head: E,
+    /// Any remaining elements.
+    pub tail: Vec<E>,
+}
+
+impl<E> NonEmpty<E> {
+    /// The number of elements, which is never zero.
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    /// Always `false`. Provided for symmetry with `len`.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Iterate over all elements.
+    pub fn iter(&self) -> impl Iterator<Item = &E> {
+        core::iter::once(&self.head).chain(self.tail.iter())
+    }
+
+    /// Convert into a plain [`Vec`].
+    pub fn into_vec(self) -> Vec<E> {
+        let mut v = self.tail;
+        v.insert(0, self.head);
+        v
+    }
+}
+
+impl<E> IntoIterator for NonEmpty<E> {
+    type Item = E;
+    type IntoIter = core::iter::Chain<core::iter::Once<E>, alloc::vec::IntoIter<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::once(self.head).chain(self.tail)
+    }
+}
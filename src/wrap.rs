@@ -0,0 +1,125 @@
+//! Wrapping values in common containers.
+
+use core::cell::Cell;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc};
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+/// Wrap values in method-position.
+///
+/// These are shorthands for the most common uses of [`Apply::apply`](crate::Apply::apply),
+/// like `.apply(Ok)` and `.apply(Arc::new)`.
+///
+/// ```
+/// use applying::Wrap;
+///
+/// fn user() -> Result<String, std::io::Error> {
+///     "Colin".to_string().ok()
+/// }
+///
+/// assert_eq!("Colin", user().unwrap());
+/// assert_eq!(Some(1), 1.some());
+/// ```
+///
+/// Note that `ok` and `err` are shadowed by the inherent methods of the same
+/// name on `Result`. `Option` has no such methods, so calling them on an
+/// `Option` wraps the option itself:
+///
+/// ```
+/// use applying::Wrap;
+///
+/// let r: Result<Option<u32>, ()> = Some(1).ok();
+/// assert_eq!(Ok(Some(1)), r);
+/// ```
+pub trait Wrap: Sized {
+    /// Wrap this value in `Ok`.
+    fn ok<E>(self) -> Result<Self, E>;
+
+    /// Wrap this value in `Err`.
+    fn err<T>(self) -> Result<T, Self>;
+
+    /// Wrap this value in `Some`.
+    fn some(self) -> Option<Self>;
+
+    /// Wrap this value in a [`Cell`].
+    fn cell(self) -> Cell<Self>;
+
+    /// Wrap this value in a [`Box`].
+    #[cfg(feature = "alloc")]
+    fn boxed(self) -> Box<Self>;
+
+    /// Wrap this value in an [`Arc`].
+    ///
+    /// ```
+    /// use applying::Wrap;
+    /// use std::sync::Arc;
+    ///
+    /// let name: Arc<str> = "Colin".into();
+    /// let names = vec![name].arced();
+    ///
+    /// assert_eq!(1, Arc::strong_count(&names));
+    /// ```
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    fn arced(self) -> Arc<Self>;
+
+    /// Wrap this value in an [`Rc`].
+    #[cfg(feature = "alloc")]
+    fn rced(self) -> Rc<Self>;
+
+    /// Pin this value on the heap.
+    #[cfg(feature = "alloc")]
+    fn pinned(self) -> Pin<Box<Self>>;
+
+    /// Wrap this value in a [`Mutex`].
+    #[cfg(feature = "std")]
+    fn mutex(self) -> Mutex<Self>;
+}
+
+impl<T> Wrap for T {
+    fn ok<E>(self) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn err<U>(self) -> Result<U, Self> {
+        Err(self)
+    }
+
+    fn some(self) -> Option<Self> {
+        Some(self)
+    }
+
+    fn cell(self) -> Cell<Self> {
+        Cell::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn boxed(self) -> Box<Self> {
+        Box::new(self)
+    }
+
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    fn arced(self) -> Arc<Self> {
+        Arc::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn rced(self) -> Rc<Self> {
+        Rc::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn pinned(self) -> Pin<Box<Self>> {
+        Box::pin(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn mutex(self) -> Mutex<Self> {
+        Mutex::new(self)
+    }
+}